@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::constants::SECS_PER_DAY;
+use crate::duration::Duration;
+use crate::units::{PreciseTime, RelativeTime, Unit};
+
+/// How to treat a day-of-month that does not exist in the target month, such
+/// as the 31st when shifting into February.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayOverflow {
+    /// Clamp the day to the last valid day of the resulting month.
+    Constrain,
+    /// Reject the shift with a [`CalendarError::InvalidDay`].
+    Reject,
+}
+
+/// Error produced while anchoring a relative unit to a calendar date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalendarError {
+    /// A shift landed on a day that does not exist under [`DayOverflow::Reject`].
+    InvalidDay { year: i64, month: u8, day: u8 },
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::InvalidDay { year, month, day } => {
+                write!(f, "day {} does not exist in {}-{:02}", day, year, month + 1)
+            }
+        }
+    }
+}
+
+impl Error for CalendarError {}
+
+/// A reference calendar date: a year, a zero-based month (`0..=11`) and a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Create a date from a year, a zero-based month and a day.
+    pub fn new(year: i64, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Shift the date forward by `n` months using field carry, resolving an
+    /// overflowing day according to `mode`.
+    pub fn add_months(self, n: i64, mode: DayOverflow) -> Result<Date, CalendarError> {
+        let total = self.month as i64 + n;
+        let year = self.year + total.div_euclid(12);
+        let month = total.rem_euclid(12) as u8;
+        let dim = days_in_month(year, month);
+
+        let day = if self.day as i64 > dim {
+            match mode {
+                DayOverflow::Constrain => dim as u8,
+                DayOverflow::Reject => {
+                    return Err(CalendarError::InvalidDay {
+                        year,
+                        month,
+                        day: self.day,
+                    })
+                }
+            }
+        } else {
+            self.day
+        };
+
+        Ok(Date { year, month, day })
+    }
+
+    /// Days since the Unix epoch (1970-01-01), for taking date differences.
+    fn days_since_epoch(self) -> i64 {
+        let m = self.month as i64 + 1;
+        let y = if m <= 2 { self.year - 1 } else { self.year };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+}
+
+/// Resolve a single [`RelativeTime`] against `anchor` into a concrete
+/// [`Duration`] — the elapsed time between the anchor and the shifted date.
+pub fn resolve_relative(
+    anchor: Date,
+    relative: &RelativeTime,
+    mode: DayOverflow,
+) -> Result<Duration, CalendarError> {
+    resolve_months(anchor, months_of(relative), mode)
+}
+
+/// Resolve a mixed [`Unit`] list against `anchor`. Relative units are summed
+/// into a single month shift, while precise units and embedded durations are
+/// added directly.
+pub fn resolve_units(
+    anchor: Date,
+    units: &[Unit],
+    mode: DayOverflow,
+) -> Result<Duration, CalendarError> {
+    let mut months = 0i64;
+    let mut acc: Duration = PreciseTime::Seconds(0).into();
+
+    for unit in units {
+        match unit {
+            Unit::RelativeTime(r) => months += months_of(r),
+            Unit::PreciseTime(p) => acc = acc + *p,
+            Unit::Duration(d) => acc = acc + *d,
+        }
+    }
+
+    Ok(acc + resolve_months(anchor, months, mode)?)
+}
+
+/// Number of months a relative unit expands to.
+fn months_of(relative: &RelativeTime) -> i64 {
+    match *relative {
+        RelativeTime::Months(n) => n as i64,
+        RelativeTime::Quartals(n) => n as i64 * 3,
+        RelativeTime::Halfs(n) => n as i64 * 6,
+        RelativeTime::Years(n) => n as i64 * 12,
+        RelativeTime::Centuries(n) => n as i64 * 1200,
+        RelativeTime::Millenniums(n) => n as i64 * 12000,
+    }
+}
+
+/// Shift `anchor` by `months` and return the elapsed duration, keeping the sign
+/// of a backward shift rather than truncating it into the unsigned magnitude.
+fn resolve_months(anchor: Date, months: i64, mode: DayOverflow) -> Result<Duration, CalendarError> {
+    let shifted = anchor.add_months(months, mode)?;
+    let secs = (shifted.days_since_epoch() - anchor.days_since_epoch()) * SECS_PER_DAY as i64;
+    Ok(Duration::from_parts(secs < 0, secs.unsigned_abs(), 0))
+}
+
+/// Days in a zero-based month of the given (possibly proleptic) year.
+fn days_in_month(year: i64, month: u8) -> i64 {
+    match month {
+        0 | 2 | 4 | 6 | 7 | 9 | 11 => 31,
+        3 | 5 | 8 | 10 => 30,
+        1 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => unreachable!("month is always 0..=11"),
+    }
+}
+
+/// Whether `year` is a leap year in the proleptic Gregorian calendar.
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
@@ -0,0 +1,18 @@
+use crate::units::TimeInt;
+
+// Seconds in the larger fixed-length units.
+pub const SECS_PER_MINUTE: TimeInt = 60;
+pub const SECS_PER_HOUR: TimeInt = 60 * SECS_PER_MINUTE;
+pub const SECS_PER_DAY: TimeInt = 24 * SECS_PER_HOUR;
+pub const SECS_PER_WEEK: TimeInt = 7 * SECS_PER_DAY;
+
+// Sub-second units per second.
+pub const MILLIS_PER_SEC: TimeInt = 1_000;
+pub const MICROS_PER_SEC: TimeInt = 1_000_000;
+pub const NANOS_PER_SEC: TimeInt = 1_000_000_000;
+pub const PICOS_PER_SEC: TimeInt = 1_000_000_000_000;
+
+// Picoseconds in each sub-second unit.
+pub const PICOS_PER_MILLI: TimeInt = PICOS_PER_SEC / MILLIS_PER_SEC;
+pub const PICOS_PER_MICRO: TimeInt = PICOS_PER_SEC / MICROS_PER_SEC;
+pub const PICOS_PER_NANO: TimeInt = PICOS_PER_SEC / NANOS_PER_SEC;
@@ -1,10 +1,17 @@
 use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
 use crate::units::{PreciseTime, TimeInt};
 use crate::constants::*;
 
 /// ISO 8601 time duration with picosecond precision.
+///
+/// The span is stored as a sign plus an unsigned magnitude split into whole
+/// seconds and a picosecond remainder. The magnitude is always normalized so
+/// that `0 <= picos < PICOS_PER_SEC`, and the zero duration is canonical: its
+/// sign is never negative.
 #[derive(Debug,Clone,Copy,PartialEq,Eq)]
 pub struct Duration {
+    negative: bool,
     secs: TimeInt,
     picos: TimeInt,
 }
@@ -13,8 +20,9 @@ impl Duration {
 
     /// Create a duration with the specified number of weeks
     /// without respect of Daylight Savings.
-    fn from_weeks(n: TimeInt) -> Self {
+    pub fn from_weeks(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n * SECS_PER_WEEK,
             picos: 0,
         }
@@ -22,64 +30,72 @@ impl Duration {
 
     /// Create a duration with the specified number of days
     /// without respect of Daylight Savings.
-    fn from_days(n: TimeInt) -> Self {
+    pub fn from_days(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n * SECS_PER_DAY,
             picos: 0,
         }
     }
 
     /// Create a duration with the specified number of hours.
-    fn from_hours(n: TimeInt) -> Self {
+    pub fn from_hours(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n * SECS_PER_HOUR,
             picos: 0,
         }
     }
 
     /// Create a duration with the specified number of minutes.
-    fn from_minutes(n: TimeInt) -> Self {
+    pub fn from_minutes(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n * SECS_PER_MINUTE,
             picos: 0,
         }
     }
 
     /// Create a duration with the specified number of seconds.
-    fn from_secs(n: TimeInt) -> Self {
+    pub fn from_secs(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n,
             picos: 0,
         }
     }
 
     /// Create a duration with the specified number of milliseconds.
-    fn from_millis(n: TimeInt) -> Self {
+    pub fn from_millis(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n / MILLIS_PER_SEC,
             picos: (n % MILLIS_PER_SEC) * PICOS_PER_MILLI,
         }
     }
 
     /// Create a duration with the specified number of microseconds.
-    fn from_micros(n: TimeInt) -> Self {
+    pub fn from_micros(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n / MICROS_PER_SEC,
             picos: (n % MICROS_PER_SEC) * PICOS_PER_MICRO,
         }
     }
 
     /// Create a duration with the specified number of nanoseconds.
-    fn from_nanos(n: TimeInt) -> Self {
+    pub fn from_nanos(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n / NANOS_PER_SEC,
             picos: (n % NANOS_PER_SEC) * PICOS_PER_NANO,
         }
     }
 
     /// Create a duration with the specified number of picoseconds.
-    fn from_picos(n: TimeInt) -> Self {
+    pub fn from_picos(n: TimeInt) -> Self {
         Self {
+            negative: false,
             secs: n / PICOS_PER_SEC,
             picos: n % PICOS_PER_SEC,
         }
@@ -87,67 +103,199 @@ impl Duration {
 
     /// Gets the length of this duration in weeks assuming that there are the
     /// standard number of seconds in a week without respect of Daylight Savings
-    fn as_weeks(&self) -> TimeInt {
+    pub fn as_weeks(&self) -> TimeInt {
         self.secs / SECS_PER_WEEK
     }
 
     /// Gets the length of this duration in days assuming that there are the
     /// standard number of seconds in a day without respect of Daylight Savings
-    fn as_days(&self) -> TimeInt {
+    pub fn as_days(&self) -> TimeInt {
         self.secs / SECS_PER_DAY
     }
 
     /// Gets the length of this duration in hours.
-    fn as_hours(&self) -> TimeInt {
+    pub fn as_hours(&self) -> TimeInt {
         self.secs / SECS_PER_HOUR
     }
 
     /// Gets the length of this duration in minutes.
-    fn as_minutes(&self) -> TimeInt {
+    pub fn as_minutes(&self) -> TimeInt {
         self.secs / SECS_PER_MINUTE
     }
 
     /// Gets the length of this duration in seconds.
-    fn as_secs(&self) -> TimeInt {
+    pub fn as_secs(&self) -> TimeInt {
         self.secs
     }
 
     /// Gets the length of this duration in milliseconds.
-    fn as_millis(&self) -> TimeInt {
+    pub fn as_millis(&self) -> TimeInt {
         (self.secs * MILLIS_PER_SEC) + (self.picos / PICOS_PER_MILLI)
     }
 
     /// Gets the length of this duration in microseconds.
-    fn as_micros(&self) -> TimeInt {
+    pub fn as_micros(&self) -> TimeInt {
         (self.secs * MICROS_PER_SEC) + (self.picos / PICOS_PER_MICRO)
     }
     /// Gets the length of this duration in nanoseconds.
-    fn as_nanos(&self) -> TimeInt {
+    pub fn as_nanos(&self) -> TimeInt {
         (self.secs * NANOS_PER_SEC) + (self.picos / PICOS_PER_NANO)
     }
 
     /// Gets the length of this duration in picoseconds.
-    fn as_picos(&self) -> TimeInt {
+    pub fn as_picos(&self) -> TimeInt {
         self.secs * PICOS_PER_SEC + self.picos
     }
 
+    /// The normalized magnitude `(secs, picos)` split, ignoring sign, with
+    /// `0 <= picos < PICOS_PER_SEC`.
+    pub(crate) fn parts(&self) -> (TimeInt, TimeInt) {
+        (self.secs, self.picos)
+    }
+
+    /// The zero duration.
+    pub const ZERO: Self = Self { negative: false, secs: 0, picos: 0 };
+
+    /// The largest representable (most positive) duration.
+    pub const MAX: Self = Self {
+        negative: false,
+        secs: TimeInt::MAX,
+        picos: PICOS_PER_SEC - 1,
+    };
+
+    /// The smallest representable (most negative) duration.
+    pub const MIN: Self = Self {
+        negative: true,
+        secs: TimeInt::MAX,
+        picos: PICOS_PER_SEC - 1,
+    };
+
+    /// Whether this duration is strictly less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The absolute value of this duration.
+    pub fn abs(&self) -> Self {
+        Self { negative: false, ..*self }
+    }
+
+    /// The sign of this duration: `-1`, `0`, or `1`.
+    pub fn signum(&self) -> i32 {
+        if self.is_zero() {
+            0
+        } else if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
+
+    /// Whether the magnitude is zero.
+    fn is_zero(&self) -> bool {
+        self.secs == 0 && self.picos == 0
+    }
+
+    /// Build a normalized duration from a sign and a possibly un-normalized
+    /// magnitude, carrying any whole seconds out of the picosecond field.
+    pub(crate) fn from_parts(negative: bool, secs: TimeInt, picos: TimeInt) -> Self {
+        let secs = secs + picos / PICOS_PER_SEC;
+        let picos = picos % PICOS_PER_SEC;
+        Self::signed(negative, secs, picos)
+    }
+
+    /// Build a duration from a sign and magnitude, collapsing a zero magnitude
+    /// to the canonical (non-negative) zero.
+    fn signed(negative: bool, secs: TimeInt, picos: TimeInt) -> Self {
+        if secs == 0 && picos == 0 {
+            Self::ZERO
+        } else {
+            Self { negative, secs, picos }
+        }
+    }
+
+    /// Order the magnitude of `self` against that of `other`, ignoring sign.
+    fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        self.secs.cmp(&other.secs).then(self.picos.cmp(&other.picos))
+    }
+
+    /// Add two durations, returning `None` on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        if self.negative == other.negative {
+            let (secs, picos) = mag_add((self.secs, self.picos), (other.secs, other.picos))?;
+            Some(Self::signed(self.negative, secs, picos))
+        } else {
+            match self.cmp_magnitude(&other) {
+                Ordering::Equal => Some(Self::ZERO),
+                Ordering::Greater => {
+                    let (secs, picos) = mag_sub((self.secs, self.picos), (other.secs, other.picos));
+                    Some(Self::signed(self.negative, secs, picos))
+                }
+                Ordering::Less => {
+                    let (secs, picos) = mag_sub((other.secs, other.picos), (self.secs, self.picos));
+                    Some(Self::signed(other.negative, secs, picos))
+                }
+            }
+        }
+    }
+
+    /// Subtract a duration, returning `None` on overflow.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(-other)
+    }
+
+    /// Scale the duration by `rhs`, returning `None` on overflow.
+    pub fn checked_mul(self, rhs: TimeInt) -> Option<Self> {
+        let total_picos = self.picos.checked_mul(rhs)?;
+        let carry = total_picos / PICOS_PER_SEC;
+        let picos = total_picos % PICOS_PER_SEC;
+        let secs = self.secs.checked_mul(rhs)?.checked_add(carry)?;
+        Some(Self::signed(self.negative, secs, picos))
+    }
+
+    /// Add two durations, clamping to [`Duration::MAX`]/[`Duration::MIN`] on
+    /// overflow.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .unwrap_or(if self.negative { Self::MIN } else { Self::MAX })
+    }
+
+    /// Subtract a duration, clamping to [`Duration::MAX`]/[`Duration::MIN`] on
+    /// overflow.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .unwrap_or(if self.negative { Self::MIN } else { Self::MAX })
+    }
+
+    /// Scale the duration by `rhs`, clamping to [`Duration::MAX`]/
+    /// [`Duration::MIN`] on overflow.
+    pub fn saturating_mul(self, rhs: TimeInt) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or(if self.negative { Self::MIN } else { Self::MAX })
+    }
+
 }
 
 impl Ord for Duration {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.secs.cmp(other.secs).then(self.picos.cmp(other.picos))
+        match (self.negative, other.negative) {
+            (false, false) => self.cmp_magnitude(other),
+            (true, true) => other.cmp_magnitude(self),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
     }
 }
 
 impl PartialOrd for Duration {
-    fn cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl From<PreciseTime> for Duration {
     fn from(t: PreciseTime) -> Self {
-        use crate::units::TimeUnit::*;
+        use crate::units::PreciseTime::*;
         match t {
             Picoseconds(n) => Self::from_picos(n),
             Nanoseconds(n) => Self::from_nanos(n),
@@ -165,8 +313,9 @@ impl From<PreciseTime> for Duration {
 impl From<std::time::Duration> for Duration {
     fn from(dur: std::time::Duration) -> Self {
         Self {
+            negative: false,
             secs: dur.as_secs(),
-            picos: dur.subsec_nanos() * PICOS_PER_NANO,
+            picos: dur.subsec_nanos() as TimeInt * PICOS_PER_NANO,
         }
     }
 }
@@ -175,13 +324,7 @@ impl Add for Duration {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        let picos = self.picos + other.picos;
-        let (picos, picos_secs) = calc_picos_secs(picos);
-
-        Self {
-            secs: self.secs + other.secs + picos_secs,
-            picos: picos,
-        }
+        self.checked_add(other).expect("overflow when adding durations")
     }
 }
 
@@ -196,38 +339,25 @@ impl Add<PreciseTime> for Duration {
 impl Sub for Duration {
     type Output = Self;
 
-    fn add(self, other: Self) -> Self {
-        let picos = self.picos - other.picos;
-        let (picos, picos_secs) = calc_picos_secs(picos);
-
-        Self {
-            secs: self.secs - other.secs - picos_secs,
-            picos: picos,
-        }
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("overflow when subtracting durations")
     }
 }
 
 impl Sub<PreciseTime> for Duration {
     type Output = Self;
 
-    fn add(self, t: PreciseTime) -> Self {
+    fn sub(self, t: PreciseTime) -> Self {
         self - Self::from(t)
     }
 }
 
-impl <T: Into<TimeInt>> Mul<T> for Duration {
+impl<T: Into<TimeInt>> Mul<T> for Duration {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self {
-        let scale: TimeInt = rhs.into();
-        let picos = self.picos * scale;
-        let secs = self.secs * scale;
-        let (picos, picos_secs) = calc_picos_secs(picos);
-
-        Self {
-            secs: secs + picos_secs,
-            picos: picos
-        }
+        self.checked_mul(rhs.into())
+            .expect("overflow when scaling a duration")
     }
 }
 
@@ -235,17 +365,99 @@ impl Neg for Duration {
     type Output = Self;
 
     fn neg(self) -> Self {
-        Self { secs: -self.secs, picos: -self.picos }
+        if self.is_zero() {
+            self
+        } else {
+            Self { negative: !self.negative, ..self }
+        }
+    }
+}
+
+/// Add two normalized magnitudes, returning `None` on seconds overflow.
+#[inline]
+fn mag_add(a: (TimeInt, TimeInt), b: (TimeInt, TimeInt)) -> Option<(TimeInt, TimeInt)> {
+    let mut picos = a.1 + b.1;
+    let mut carry = 0;
+    if picos >= PICOS_PER_SEC {
+        picos -= PICOS_PER_SEC;
+        carry = 1;
     }
+    let secs = a.0.checked_add(b.0)?.checked_add(carry)?;
+    Some((secs, picos))
 }
 
+/// Subtract the magnitude `b` from the magnitude `a`, assuming `a >= b`.
 #[inline]
-fn calc_picos_secs(picos: TimeInt) -> (TimeInt, TimeInt) {
-    if picos.abs() => PICOS_PER_SEC {
-        picos_secs = picos / PICOS_PER_SEC;
-        picos = picos % PICOS_PER_SEC;
-        (picos, picos_secs)
+fn mag_sub(a: (TimeInt, TimeInt), b: (TimeInt, TimeInt)) -> (TimeInt, TimeInt) {
+    if a.1 >= b.1 {
+        (a.0 - b.0, a.1 - b.1)
     } else {
-        (picos, 0)
+        (a.0 - b.0 - 1, a.1 + PICOS_PER_SEC - b.1)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Duration {
+    /// Render the duration as a signed ISO 8601 string, e.g. `-PT1.5S`.
+    fn to_iso8601(self) -> String {
+        use crate::iso8601::Iso8601Duration;
+        use crate::units::Unit;
+
+        let iso = Iso8601Duration::from(vec![
+            Unit::PreciseTime(PreciseTime::Seconds(self.secs)),
+            Unit::PreciseTime(PreciseTime::Picoseconds(self.picos)),
+        ])
+        .to_string();
+        if self.negative {
+            format!("-{}", iso)
+        } else {
+            iso
+        }
+    }
+
+    /// Parse a signed ISO 8601 string produced by [`Duration::to_iso8601`].
+    fn from_iso8601(s: &str) -> Result<Self, crate::iso8601::ParseError> {
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let duration = Duration::parse(rest)?;
+        Ok(if negative { -duration } else { duration })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Duration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_iso8601())
+        } else {
+            use serde::ser::SerializeTuple;
+            let mut tuple = serializer.serialize_tuple(3)?;
+            tuple.serialize_element(&self.negative)?;
+            tuple.serialize_element(&self.secs)?;
+            tuple.serialize_element(&self.picos)?;
+            tuple.end()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+            Self::from_iso8601(&s).map_err(serde::de::Error::custom)
+        } else {
+            let (negative, secs, picos) =
+                <(bool, TimeInt, TimeInt) as serde::Deserialize>::deserialize(deserializer)?;
+            Ok(Self::signed(negative, secs, picos))
+        }
     }
 }
@@ -0,0 +1,303 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::constants::*;
+use crate::duration::Duration;
+use crate::units::{PreciseTime, RelativeTime, TimeInt, Unit};
+
+/// A parsed ISO 8601 duration (`PnYnMnWnDTnHnMnS`).
+///
+/// The year and month designators are not fixed-length, so they are kept as
+/// [`RelativeTime`] units; everything from weeks down maps to [`PreciseTime`].
+/// Holding the components as a [`Unit`] list keeps the relative and precise
+/// parts separate until a calendar anchor is available to resolve them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iso8601Duration {
+    units: Vec<Unit>,
+}
+
+/// Error returned when an ISO 8601 duration string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string did not start with the mandatory `P`.
+    MissingP,
+    /// A number was not followed by a unit designator.
+    MissingDesignator,
+    /// An unexpected character was encountered.
+    Unexpected(char),
+    /// A numeric field could not be parsed.
+    InvalidNumber(String),
+    /// A fractional value was given for a field other than seconds.
+    UnexpectedFraction(char),
+    /// A `T` separator was present but no time components followed it.
+    EmptyTimeSection,
+    /// The string resolves to a bare [`Duration`] but carries relative units.
+    RelativeComponent,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingP => write!(f, "duration must start with 'P'"),
+            ParseError::MissingDesignator => write!(f, "number is missing a unit designator"),
+            ParseError::Unexpected(c) => write!(f, "unexpected character '{}'", c),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number '{}'", s),
+            ParseError::UnexpectedFraction(c) => {
+                write!(f, "fractional value not allowed for designator '{}'", c)
+            }
+            ParseError::EmptyTimeSection => write!(f, "'T' present but no time components"),
+            ParseError::RelativeComponent => {
+                write!(f, "relative units cannot be resolved to a bare Duration")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl Iso8601Duration {
+    /// Parse an ISO 8601 duration string such as `P3Y6M4DT12H30M5S`.
+    ///
+    /// Either a comma or a period is accepted as the decimal separator on the
+    /// seconds field, and the fractional part is honoured down to picoseconds.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut chars = input.chars().peekable();
+        match chars.next() {
+            Some('P') => {}
+            _ => return Err(ParseError::MissingP),
+        }
+
+        let mut units = Vec::new();
+        let mut in_time = false;
+        let mut time_components = 0usize;
+
+        while let Some(&c) = chars.peek() {
+            if c == 'T' {
+                chars.next();
+                in_time = true;
+                continue;
+            }
+
+            let mut num = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_digit() || c == '.' || c == ',' {
+                    num.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            if num.is_empty() {
+                return Err(ParseError::Unexpected(c));
+            }
+
+            let designator = chars.next().ok_or(ParseError::MissingDesignator)?;
+            let (int, frac_picos) = split_number(&num)?;
+
+            if frac_picos.is_some() && !(in_time && designator == 'S') {
+                return Err(ParseError::UnexpectedFraction(designator));
+            }
+
+            if in_time {
+                match designator {
+                    'H' => units.push(Unit::PreciseTime(PreciseTime::Hours(int))),
+                    'M' => units.push(Unit::PreciseTime(PreciseTime::Minutes(int))),
+                    'S' => {
+                        units.push(Unit::PreciseTime(PreciseTime::Seconds(int)));
+                        if let Some(picos) = frac_picos {
+                            if picos > 0 {
+                                units.push(Unit::PreciseTime(PreciseTime::Picoseconds(picos)));
+                            }
+                        }
+                    }
+                    other => return Err(ParseError::Unexpected(other)),
+                }
+                time_components += 1;
+            } else {
+                match designator {
+                    'Y' => units.push(Unit::RelativeTime(RelativeTime::Years(int))),
+                    'M' => units.push(Unit::RelativeTime(RelativeTime::Months(int))),
+                    'W' => units.push(Unit::PreciseTime(PreciseTime::Weeks(int))),
+                    'D' => units.push(Unit::PreciseTime(PreciseTime::Days(int))),
+                    other => return Err(ParseError::Unexpected(other)),
+                }
+            }
+        }
+
+        if in_time && time_components == 0 {
+            return Err(ParseError::EmptyTimeSection);
+        }
+
+        Ok(Self { units })
+    }
+
+    /// The parsed components, in the order they appeared in the string.
+    pub fn units(&self) -> &[Unit] {
+        &self.units
+    }
+
+    /// Consume the parsed duration, yielding its [`Unit`] list.
+    pub fn into_units(self) -> Vec<Unit> {
+        self.units
+    }
+
+    /// Render the duration back into its canonical ISO 8601 form.
+    pub fn to_iso8601(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl From<Vec<Unit>> for Iso8601Duration {
+    fn from(units: Vec<Unit>) -> Self {
+        Self { units }
+    }
+}
+
+impl FromStr for Iso8601Duration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Iso8601Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut years = 0;
+        let mut months = 0;
+        let mut weeks = 0;
+        let mut days = 0;
+        let mut hours = 0;
+        let mut minutes = 0;
+        let mut seconds = 0;
+        let mut picos = 0;
+
+        for unit in &self.units {
+            match unit {
+                Unit::PreciseTime(p) => match p {
+                    PreciseTime::Picoseconds(n) => picos += n,
+                    PreciseTime::Nanoseconds(n) => picos += n * PICOS_PER_NANO,
+                    PreciseTime::Microseconds(n) => picos += n * PICOS_PER_MICRO,
+                    PreciseTime::Milliseconds(n) => picos += n * PICOS_PER_MILLI,
+                    PreciseTime::Seconds(n) => seconds += n,
+                    PreciseTime::Minutes(n) => minutes += n,
+                    PreciseTime::Hours(n) => hours += n,
+                    PreciseTime::Days(n) => days += n,
+                    PreciseTime::Weeks(n) => weeks += n,
+                },
+                Unit::RelativeTime(r) => match r {
+                    RelativeTime::Months(n) => months += n,
+                    RelativeTime::Quartals(n) => months += n * 3,
+                    RelativeTime::Halfs(n) => months += n * 6,
+                    RelativeTime::Years(n) => years += n,
+                    RelativeTime::Centuries(n) => years += n * 100,
+                    RelativeTime::Millenniums(n) => years += n * 1000,
+                },
+                Unit::Duration(d) => {
+                    let (s, p) = d.parts();
+                    seconds += s;
+                    picos += p;
+                }
+            }
+        }
+
+        seconds += picos / PICOS_PER_SEC;
+        picos %= PICOS_PER_SEC;
+
+        write!(f, "P")?;
+        if years > 0 {
+            write!(f, "{}Y", years)?;
+        }
+        if months > 0 {
+            write!(f, "{}M", months)?;
+        }
+        if weeks > 0 {
+            write!(f, "{}W", weeks)?;
+        }
+        if days > 0 {
+            write!(f, "{}D", days)?;
+        }
+
+        let has_time = hours > 0 || minutes > 0 || seconds > 0 || picos > 0;
+        let empty = !(years > 0 || months > 0 || weeks > 0 || days > 0 || has_time);
+        if has_time || empty {
+            write!(f, "T")?;
+            if hours > 0 {
+                write!(f, "{}H", hours)?;
+            }
+            if minutes > 0 {
+                write!(f, "{}M", minutes)?;
+            }
+            if picos > 0 {
+                let frac = format!("{:012}", picos);
+                write!(f, "{}.{}S", seconds, frac.trim_end_matches('0'))?;
+            } else {
+                // Always emit the seconds field so the zero duration renders as PT0S.
+                write!(f, "{}S", seconds)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Duration {
+    /// Parse an ISO 8601 duration that contains only precise components.
+    ///
+    /// Strings carrying year or month designators cannot be resolved without a
+    /// calendar anchor and yield [`ParseError::RelativeComponent`].
+    pub fn parse(input: &str) -> Result<Duration, ParseError> {
+        let parsed = Iso8601Duration::parse(input)?;
+        let mut acc: Duration = PreciseTime::Seconds(0).into();
+        for unit in parsed.into_units() {
+            match unit {
+                Unit::PreciseTime(p) => acc = acc + p,
+                Unit::Duration(d) => acc = acc + d,
+                Unit::RelativeTime(_) => return Err(ParseError::RelativeComponent),
+            }
+        }
+        Ok(acc)
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Duration::parse(s)
+    }
+}
+
+/// Split a numeric field into an integer part and an optional fractional part
+/// expressed in picoseconds (truncating anything finer than a picosecond).
+fn split_number(num: &str) -> Result<(TimeInt, Option<TimeInt>), ParseError> {
+    let normalized = num.replace(',', ".");
+    match normalized.split_once('.') {
+        None => {
+            let int = normalized
+                .parse()
+                .map_err(|_| ParseError::InvalidNumber(num.to_string()))?;
+            Ok((int, None))
+        }
+        Some((int_part, frac_part)) => {
+            let int = int_part
+                .parse()
+                .map_err(|_| ParseError::InvalidNumber(num.to_string()))?;
+            let mut frac = String::with_capacity(12);
+            for c in frac_part.chars().take(12) {
+                if !c.is_ascii_digit() {
+                    return Err(ParseError::InvalidNumber(num.to_string()));
+                }
+                frac.push(c);
+            }
+            while frac.len() < 12 {
+                frac.push('0');
+            }
+            let picos = frac
+                .parse()
+                .map_err(|_| ParseError::InvalidNumber(num.to_string()))?;
+            Ok((int, Some(picos)))
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! `xrono` — time durations with picosecond precision.
+//!
+//! Optional `serde` support is gated behind the `serde` cargo feature. With it
+//! enabled the unit enums serialize as tagged enums and [`Duration`] uses its
+//! ISO 8601 string in human-readable formats and a compact `(sign, secs,
+//! picos)` tuple in binary formats such as bincode.
+
+pub mod calendar;
+pub mod constants;
+pub mod duration;
+pub mod iso8601;
+pub mod numerical;
+pub mod posix;
+pub mod units;
+
+pub use crate::calendar::{CalendarError, Date, DayOverflow};
+pub use crate::duration::Duration;
+pub use crate::iso8601::{Iso8601Duration, ParseError};
+pub use crate::numerical::NumericalDuration;
+pub use crate::posix::{OutOfRangeError, Timespec, Timeval};
+pub use crate::units::{PreciseTime, RelativeTime, Unit};
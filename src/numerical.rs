@@ -0,0 +1,110 @@
+use crate::duration::Duration;
+use crate::units::PreciseTime;
+
+/// Ergonomic construction of [`Duration`] values from plain integers.
+///
+/// Bringing the trait into scope lets a count be turned straight into a
+/// duration, e.g. `5.seconds()` or `10.picos()`, without building a
+/// [`PreciseTime`] and converting it by hand. Every method mirrors a
+/// [`PreciseTime`] variant so the sugar stays in lockstep with the enum.
+pub trait NumericalDuration {
+    /// A duration of this many picoseconds.
+    fn picos(self) -> Duration;
+    /// A duration of this many nanoseconds.
+    fn nanos(self) -> Duration;
+    /// A duration of this many microseconds.
+    fn micros(self) -> Duration;
+    /// A duration of this many milliseconds.
+    fn millis(self) -> Duration;
+    /// A duration of this many seconds.
+    fn seconds(self) -> Duration;
+    /// A duration of this many minutes.
+    fn minutes(self) -> Duration;
+    /// A duration of this many hours.
+    fn hours(self) -> Duration;
+    /// A duration of this many days, without respect of Daylight Savings.
+    fn days(self) -> Duration;
+    /// A duration of this many weeks, without respect of Daylight Savings.
+    fn weeks(self) -> Duration;
+}
+
+/// Negate `d` when the source integer was negative.
+#[inline]
+fn signed(d: Duration, negative: bool) -> Duration {
+    if negative {
+        -d
+    } else {
+        d
+    }
+}
+
+macro_rules! impl_numerical_duration_unsigned {
+    ($($t:ty),+) => {$(
+        impl NumericalDuration for $t {
+            fn picos(self) -> Duration {
+                PreciseTime::Picoseconds(self as crate::units::TimeInt).into()
+            }
+            fn nanos(self) -> Duration {
+                PreciseTime::Nanoseconds(self as crate::units::TimeInt).into()
+            }
+            fn micros(self) -> Duration {
+                PreciseTime::Microseconds(self as crate::units::TimeInt).into()
+            }
+            fn millis(self) -> Duration {
+                PreciseTime::Milliseconds(self as crate::units::TimeInt).into()
+            }
+            fn seconds(self) -> Duration {
+                PreciseTime::Seconds(self as crate::units::TimeInt).into()
+            }
+            fn minutes(self) -> Duration {
+                PreciseTime::Minutes(self as crate::units::TimeInt).into()
+            }
+            fn hours(self) -> Duration {
+                PreciseTime::Hours(self as crate::units::TimeInt).into()
+            }
+            fn days(self) -> Duration {
+                PreciseTime::Days(self as crate::units::TimeInt).into()
+            }
+            fn weeks(self) -> Duration {
+                PreciseTime::Weeks(self as crate::units::TimeInt).into()
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_numerical_duration_signed {
+    ($($t:ty),+) => {$(
+        impl NumericalDuration for $t {
+            fn picos(self) -> Duration {
+                signed(PreciseTime::Picoseconds(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn nanos(self) -> Duration {
+                signed(PreciseTime::Nanoseconds(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn micros(self) -> Duration {
+                signed(PreciseTime::Microseconds(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn millis(self) -> Duration {
+                signed(PreciseTime::Milliseconds(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn seconds(self) -> Duration {
+                signed(PreciseTime::Seconds(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn minutes(self) -> Duration {
+                signed(PreciseTime::Minutes(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn hours(self) -> Duration {
+                signed(PreciseTime::Hours(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn days(self) -> Duration {
+                signed(PreciseTime::Days(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+            fn weeks(self) -> Duration {
+                signed(PreciseTime::Weeks(self.unsigned_abs() as crate::units::TimeInt).into(), self < 0)
+            }
+        }
+    )+};
+}
+
+impl_numerical_duration_unsigned!(u8, u16, u32, u64);
+impl_numerical_duration_signed!(i8, i16, i32, i64);
@@ -0,0 +1,94 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use crate::constants::{MICROS_PER_SEC, NANOS_PER_SEC, PICOS_PER_MICRO, PICOS_PER_NANO};
+use crate::duration::Duration;
+
+/// POSIX `struct timespec`: whole seconds plus a nanosecond remainder.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timespec {
+    pub tv_sec: i64,
+    pub tv_nsec: i64,
+}
+
+/// POSIX `struct timeval`: whole seconds plus a microsecond remainder.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeval {
+    pub tv_sec: i64,
+    pub tv_usec: i64,
+}
+
+/// Error returned when a [`Duration`] is too large to fit a POSIX time struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRangeError;
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duration does not fit a POSIX time struct")
+    }
+}
+
+impl Error for OutOfRangeError {}
+
+/// Truncates the picosecond field to nanoseconds; any finer precision is lost.
+///
+/// The sign is carried by `tv_sec` and `tv_nsec` is normalized into
+/// `[0, 1_000_000_000)` as POSIX requires, so e.g. `-1.5s` becomes
+/// `{ tv_sec: -2, tv_nsec: 500_000_000 }`.
+impl TryFrom<Duration> for Timespec {
+    type Error = OutOfRangeError;
+
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        let (secs, picos) = d.parts();
+        let sign = if d.is_negative() { -1 } else { 1 };
+        let total_nanos =
+            sign * (secs as i128 * NANOS_PER_SEC as i128 + (picos / PICOS_PER_NANO) as i128);
+        let tv_sec =
+            i64::try_from(total_nanos.div_euclid(NANOS_PER_SEC as i128)).map_err(|_| OutOfRangeError)?;
+        let tv_nsec = total_nanos.rem_euclid(NANOS_PER_SEC as i128) as i64;
+        Ok(Timespec { tv_sec, tv_nsec })
+    }
+}
+
+impl From<Timespec> for Duration {
+    fn from(ts: Timespec) -> Self {
+        let total_nanos = ts.tv_sec as i128 * NANOS_PER_SEC as i128 + ts.tv_nsec as i128;
+        let mag = total_nanos.unsigned_abs();
+        let secs = (mag / NANOS_PER_SEC as u128) as crate::units::TimeInt;
+        let picos = (mag % NANOS_PER_SEC as u128) as crate::units::TimeInt * PICOS_PER_NANO;
+        Duration::from_parts(total_nanos < 0, secs, picos)
+    }
+}
+
+/// Truncates the picosecond field to microseconds; any finer precision is lost.
+///
+/// The sign is carried by `tv_sec` and `tv_usec` is normalized into
+/// `[0, 1_000_000)` as POSIX requires, so e.g. `-1.5s` becomes
+/// `{ tv_sec: -2, tv_usec: 500_000 }`.
+impl TryFrom<Duration> for Timeval {
+    type Error = OutOfRangeError;
+
+    fn try_from(d: Duration) -> Result<Self, Self::Error> {
+        let (secs, picos) = d.parts();
+        let sign = if d.is_negative() { -1 } else { 1 };
+        let total_micros =
+            sign * (secs as i128 * MICROS_PER_SEC as i128 + (picos / PICOS_PER_MICRO) as i128);
+        let tv_sec = i64::try_from(total_micros.div_euclid(MICROS_PER_SEC as i128))
+            .map_err(|_| OutOfRangeError)?;
+        let tv_usec = total_micros.rem_euclid(MICROS_PER_SEC as i128) as i64;
+        Ok(Timeval { tv_sec, tv_usec })
+    }
+}
+
+impl From<Timeval> for Duration {
+    fn from(tv: Timeval) -> Self {
+        let total_micros = tv.tv_sec as i128 * MICROS_PER_SEC as i128 + tv.tv_usec as i128;
+        let mag = total_micros.unsigned_abs();
+        let secs = (mag / MICROS_PER_SEC as u128) as crate::units::TimeInt;
+        let picos = (mag % MICROS_PER_SEC as u128) as crate::units::TimeInt * PICOS_PER_MICRO;
+        Duration::from_parts(total_micros < 0, secs, picos)
+    }
+}
@@ -1,6 +1,10 @@
+use crate::duration::Duration;
+
 pub type TimeInt = u64;
 
 // Time units that can be easyly converted to seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PreciseTime {
     Picoseconds(TimeInt),
     Nanoseconds(TimeInt),
@@ -14,6 +18,8 @@ pub enum PreciseTime {
 }
 
 // Time units that can be converted to seconds only relative to some date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelativeTime {
     Months(TimeInt),
     Quartals(TimeInt),
@@ -23,6 +29,8 @@ pub enum RelativeTime {
     Millenniums(TimeInt),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Unit {
     PreciseTime(PreciseTime),
     RelativeTime(RelativeTime),
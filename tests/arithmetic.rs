@@ -0,0 +1,30 @@
+use xrono::{Duration, NumericalDuration};
+
+#[test]
+fn checked_add_detects_overflow() {
+    assert_eq!(Duration::MAX.checked_add(1u32.seconds()), None);
+    assert_eq!(
+        2u32.seconds().checked_add(3u32.seconds()),
+        Some(5u32.seconds())
+    );
+}
+
+#[test]
+fn checked_mul_detects_overflow() {
+    assert_eq!(Duration::MAX.checked_mul(2), None);
+    assert_eq!(3u32.seconds().checked_mul(4), Some(12u32.seconds()));
+}
+
+#[test]
+fn saturating_ops_clamp_to_bounds() {
+    assert_eq!(Duration::MAX.saturating_add(1u32.seconds()), Duration::MAX);
+    assert_eq!(Duration::MIN.saturating_sub(1u32.seconds()), Duration::MIN);
+    assert_eq!(Duration::MAX.saturating_mul(2), Duration::MAX);
+}
+
+#[test]
+fn zero_is_the_additive_identity() {
+    let d = 7u32.hours();
+    assert_eq!(Duration::ZERO.saturating_add(d), d);
+    assert_eq!(d.checked_sub(d), Some(Duration::ZERO));
+}
@@ -0,0 +1,49 @@
+use xrono::{CalendarError, Date, DayOverflow, Duration, NumericalDuration};
+use xrono::calendar::{resolve_relative, resolve_units};
+use xrono::units::{PreciseTime, RelativeTime, Unit};
+
+#[test]
+fn jan_31_plus_one_month_constrains_to_feb() {
+    let anchor = Date::new(2021, 0, 31);
+    let shifted = anchor.add_months(1, DayOverflow::Constrain).unwrap();
+    assert_eq!(shifted, Date::new(2021, 1, 28));
+}
+
+#[test]
+fn jan_31_plus_one_month_rejects() {
+    let anchor = Date::new(2021, 0, 31);
+    assert_eq!(
+        anchor.add_months(1, DayOverflow::Reject),
+        Err(CalendarError::InvalidDay { year: 2021, month: 1, day: 31 })
+    );
+}
+
+#[test]
+fn leap_year_gives_feb_29() {
+    let anchor = Date::new(2020, 0, 31);
+    let shifted = anchor.add_months(1, DayOverflow::Constrain).unwrap();
+    assert_eq!(shifted, Date::new(2020, 1, 29));
+}
+
+#[test]
+fn one_year_resolves_to_365_days() {
+    let anchor = Date::new(2021, 0, 1);
+    let resolved =
+        resolve_relative(anchor, &RelativeTime::Years(1), DayOverflow::Constrain).unwrap();
+    let expected: Duration = 365u32.days();
+    assert_eq!(resolved, expected);
+}
+
+#[test]
+fn resolve_units_mixes_precise_duration_and_relative() {
+    // 2021-01-01 + 1 month (31 days) + 2 days + 12 hours.
+    let anchor = Date::new(2021, 0, 1);
+    let units = [
+        Unit::RelativeTime(RelativeTime::Months(1)),
+        Unit::PreciseTime(PreciseTime::Days(2)),
+        Unit::Duration(12u32.hours()),
+    ];
+    let resolved = resolve_units(anchor, &units[..], DayOverflow::Constrain);
+    let expected = 31u32.days() + 2u32.days() + 12u32.hours();
+    assert_eq!(resolved.unwrap(), expected);
+}
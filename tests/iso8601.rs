@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use xrono::{Duration, Iso8601Duration, ParseError};
+
+#[test]
+fn parses_full_grammar() {
+    let iso = Iso8601Duration::parse("P3Y6M4DT12H30M5S").unwrap();
+    assert_eq!(iso.units().len(), 6);
+    assert_eq!(iso.to_iso8601(), "P3Y6M4DT12H30M5S");
+}
+
+#[test]
+fn zero_duration_renders_as_pt0s() {
+    let iso = Iso8601Duration::parse("PT0S").unwrap();
+    assert_eq!(iso.to_iso8601(), "PT0S");
+    assert_eq!(Duration::from_str("PT0S").unwrap(), Duration::ZERO);
+}
+
+#[test]
+fn rejects_empty_time_section() {
+    assert_eq!(Iso8601Duration::parse("P1YT"), Err(ParseError::EmptyTimeSection));
+    assert_eq!(Iso8601Duration::parse("PT"), Err(ParseError::EmptyTimeSection));
+}
+
+#[test]
+fn comma_and_period_decimal_separators_agree() {
+    let comma = Iso8601Duration::parse("PT1,5S").unwrap();
+    let period = Iso8601Duration::parse("PT1.5S").unwrap();
+    assert_eq!(comma, period);
+    assert_eq!(period.to_iso8601(), "PT1.5S");
+}
+
+#[test]
+fn missing_leading_p_is_rejected() {
+    assert_eq!(Iso8601Duration::parse("3Y"), Err(ParseError::MissingP));
+}
+
+#[test]
+fn relative_components_cannot_resolve_to_bare_duration() {
+    assert_eq!(Duration::parse("P1Y"), Err(ParseError::RelativeComponent));
+}
@@ -0,0 +1,22 @@
+use xrono::{Duration, NumericalDuration};
+
+#[test]
+fn unsigned_units_build_positive_durations() {
+    assert_eq!(5u32.seconds(), Duration::from(std::time::Duration::from_secs(5)));
+    assert_eq!(2u8.weeks(), 14u8.days());
+    assert_eq!(3u64.hours(), 180u64.minutes());
+}
+
+#[test]
+fn signed_units_preserve_sign() {
+    let five = 5i32.seconds();
+    assert_eq!((-5i32).seconds(), -five);
+    assert!((-5i32).seconds().is_negative());
+    assert_eq!((-5i32).seconds().signum(), -1);
+}
+
+#[test]
+fn signed_zero_is_canonical() {
+    assert!(!0i32.seconds().is_negative());
+    assert_eq!(0i32.seconds(), 0u32.seconds());
+}
@@ -0,0 +1,44 @@
+use std::convert::TryFrom;
+
+use xrono::constants::NANOS_PER_SEC;
+use xrono::{Duration, NumericalDuration, Timespec, Timeval};
+
+#[test]
+fn timespec_round_trips_nanosecond_precision() {
+    let d = 3u32.seconds() + 250u32.nanos();
+    let ts = Timespec::try_from(d).unwrap();
+    assert_eq!(ts, Timespec { tv_sec: 3, tv_nsec: 250 });
+    assert_eq!(Duration::from(ts), d);
+}
+
+#[test]
+fn timespec_truncates_sub_nanosecond_picos() {
+    let d = 1u32.seconds() + 1u32.picos();
+    let ts = Timespec::try_from(d).unwrap();
+    assert_eq!(ts, Timespec { tv_sec: 1, tv_nsec: 0 });
+}
+
+#[test]
+fn negative_duration_normalizes_like_posix() {
+    // POSIX carries the sign on tv_sec and keeps tv_nsec in [0, 1e9).
+    let d = -(2u32.seconds() + 500u32.nanos());
+    let ts = Timespec::try_from(d).unwrap();
+    assert_eq!(ts, Timespec { tv_sec: -3, tv_nsec: NANOS_PER_SEC as i64 - 500 });
+    assert_eq!(Duration::from(ts), d);
+}
+
+#[test]
+fn decodes_kernel_normalized_negative_timespec() {
+    // A genuine kernel struct for -1.5s, not something this crate produced.
+    let ts = Timespec { tv_sec: -2, tv_nsec: 500_000_000 };
+    let expected = -(1u32.seconds() + 500u32.millis());
+    assert_eq!(Duration::from(ts), expected);
+}
+
+#[test]
+fn timeval_uses_microseconds() {
+    let d = 4u32.seconds() + 7u32.micros();
+    let tv = Timeval::try_from(d).unwrap();
+    assert_eq!(tv, Timeval { tv_sec: 4, tv_usec: 7 });
+    assert_eq!(Duration::from(tv), d);
+}
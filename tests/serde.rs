@@ -0,0 +1,29 @@
+#![cfg(feature = "serde")]
+
+use xrono::{Duration, NumericalDuration};
+
+#[test]
+fn duration_human_readable_is_iso8601() {
+    let d = 1u32.seconds() + Duration::from(std::time::Duration::from_millis(500));
+    let json = serde_json::to_string(&d).unwrap();
+    assert_eq!(json, "\"PT1.5S\"");
+    let back: Duration = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, d);
+}
+
+#[test]
+fn duration_binary_round_trips_exactly() {
+    let d = -(90u32.minutes()) + Duration::from(std::time::Duration::from_nanos(7));
+    let bytes = bincode::serialize(&d).unwrap();
+    let back: Duration = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, d);
+}
+
+#[test]
+fn negative_duration_round_trips_as_string() {
+    let d = -(5u32.seconds());
+    let json = serde_json::to_string(&d).unwrap();
+    assert_eq!(json, "\"-PT5S\"");
+    let back: Duration = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, d);
+}
@@ -0,0 +1,32 @@
+use xrono::{Duration, NumericalDuration};
+
+#[test]
+fn subtracting_a_larger_span_is_negative() {
+    let r = 3u32.seconds() - 5u32.seconds();
+    assert_eq!(r, -(2u32.seconds()));
+    assert!(r.is_negative());
+}
+
+#[test]
+fn neg_round_trips() {
+    let d = 90u32.minutes();
+    assert_eq!(-(-d), d);
+    assert!(!(-Duration::ZERO).is_negative());
+}
+
+#[test]
+fn abs_and_signum() {
+    let neg = -(4u32.hours());
+    assert_eq!(neg.abs(), 4u32.hours());
+    assert_eq!(neg.signum(), -1);
+    assert_eq!(Duration::ZERO.signum(), 0);
+    assert_eq!(4u32.hours().signum(), 1);
+}
+
+#[test]
+fn ordering_respects_sign() {
+    assert!(-(1u32.seconds()) < Duration::ZERO);
+    assert!(Duration::ZERO < 1u32.seconds());
+    assert!(-(5u32.seconds()) < -(1u32.seconds()));
+    assert!(Duration::MIN < Duration::MAX);
+}